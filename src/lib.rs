@@ -2,7 +2,7 @@
 
 use std::{io::Cursor, str::FromStr};
 
-use bitcoin::{consensus::Decodable, Transaction};
+use bitcoin::{absolute, consensus::Decodable, transaction, Amount, ScriptBuf, Transaction, TxOut};
 use napi::bindgen_prelude::{BigInt, Buffer};
 use ordinals::{Artifact, SpacedRune};
 
@@ -181,23 +181,101 @@ impl TryFrom<Runestone> for ordinals::Runestone {
   }
 }
 
-#[napi]
-pub fn decipher(transaction: String) -> Option<Runestone> {
-  let hex = hex::decode(transaction).ok()?;
-  let mut cursor = Cursor::new(hex);
-  let transaction = Transaction::consensus_decode(&mut cursor).ok()?;
-
-  let runestone = ordinals::Runestone::decipher(&transaction)
-    .ok_or_else(|| String::from("No artifact found for transaction"))
-    .ok()?;
-  match runestone {
-    Artifact::Cenotaph(_) => None,
-    Artifact::Runestone(runestone) => {
-      return Some(runestone.into());
+#[napi(object)]
+#[derive(Clone)]
+pub struct Cenotaph {
+  pub flaws: Vec<String>,
+  pub etching: Option<String>,
+  pub mint: Option<String>,
+}
+
+impl From<ordinals::Cenotaph> for Cenotaph {
+  fn from(value: ordinals::Cenotaph) -> Self {
+    Self {
+      flaws: value
+        .flaw
+        .map(flaw_tag)
+        .map(String::from)
+        .into_iter()
+        .collect(),
+      etching: value.etching.map(|v| v.to_string()),
+      mint: value.mint.map(|v| v.to_string()),
     }
   }
 }
 
+fn flaw_tag(flaw: ordinals::Flaw) -> &'static str {
+  match flaw {
+    ordinals::Flaw::EdictOutput => "edictOutput",
+    ordinals::Flaw::EdictRuneId => "edictRuneId",
+    ordinals::Flaw::InvalidScript => "invalidScript",
+    ordinals::Flaw::Opcode => "opcode",
+    ordinals::Flaw::SupplyOverflow => "supplyOverflow",
+    ordinals::Flaw::TrailingIntegers => "trailingIntegers",
+    ordinals::Flaw::TruncatedField => "truncatedField",
+    ordinals::Flaw::UnrecognizedEvenTag => "unrecognizedEvenTag",
+    ordinals::Flaw::UnrecognizedFlag => "unrecognizedFlag",
+    ordinals::Flaw::Varint => "varint",
+  }
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct DecipherResult {
+  pub kind: String,
+  pub runestone: Option<Runestone>,
+  pub cenotaph: Option<Cenotaph>,
+}
+
+impl From<Artifact> for DecipherResult {
+  fn from(value: Artifact) -> Self {
+    match value {
+      Artifact::Cenotaph(cenotaph) => Self {
+        kind: "cenotaph".into(),
+        runestone: None,
+        cenotaph: Some(cenotaph.into()),
+      },
+      Artifact::Runestone(runestone) => Self {
+        kind: "runestone".into(),
+        runestone: Some(runestone.into()),
+        cenotaph: None,
+      },
+    }
+  }
+}
+
+fn decode_transaction(bytes: Vec<u8>) -> Option<Transaction> {
+  let mut cursor = Cursor::new(bytes);
+  Transaction::consensus_decode(&mut cursor).ok()
+}
+
+fn decipher_transaction(transaction: &Transaction) -> Option<DecipherResult> {
+  Some(ordinals::Runestone::decipher(transaction)?.into())
+}
+
+#[napi]
+pub fn decipher(transaction: String) -> Option<DecipherResult> {
+  let transaction = decode_transaction(hex::decode(transaction).ok()?)?;
+  decipher_transaction(&transaction)
+}
+
+#[napi]
+pub fn decipher_buffer(tx: Buffer) -> Option<DecipherResult> {
+  let transaction = decode_transaction(tx.to_vec())?;
+  decipher_transaction(&transaction)
+}
+
+#[napi]
+pub fn decipher_batch(txs: Vec<Buffer>) -> Vec<Option<DecipherResult>> {
+  txs
+    .into_iter()
+    .map(|tx| {
+      let transaction = decode_transaction(tx.to_vec())?;
+      decipher_transaction(&transaction)
+    })
+    .collect()
+}
+
 #[napi]
 pub fn encipher(data: Runestone) -> Option<Buffer> {
   let runestone: ordinals::Runestone = data.try_into().ok()?;
@@ -205,3 +283,139 @@ pub fn encipher(data: Runestone) -> Option<Buffer> {
   Some(res.into())
 }
 
+#[napi(object)]
+#[derive(Clone)]
+pub struct RunestoneOutput {
+  pub value: BigInt,
+  pub script_pub_key: Buffer,
+}
+
+#[napi]
+pub fn enscript_output(data: Runestone) -> Option<RunestoneOutput> {
+  let runestone: ordinals::Runestone = data.try_into().ok()?;
+  let script: Vec<u8> = runestone.encipher().into();
+  Some(RunestoneOutput {
+    value: 0u64.into(),
+    script_pub_key: script.into(),
+  })
+}
+
+// Default number of non-OP_RETURN outputs to probe with when the caller
+// doesn't know the real shape of the transaction yet.
+const DEFAULT_VALIDATION_OUTPUTS: u32 = 2;
+
+// Hard ceiling on the probe transaction's output count so a malformed
+// `outputCount` (or a caller blindly forwarding an attacker-controlled
+// value) can't turn validation itself into a multi-gigabyte allocation.
+const MAX_VALIDATION_OUTPUTS: u32 = u16::MAX as u32;
+
+#[napi]
+pub fn validate_runestone(data: Runestone, output_count: Option<u32>) -> Option<Vec<String>> {
+  let runestone: ordinals::Runestone = data.try_into().ok()?;
+  let script: Vec<u8> = runestone.encipher().into();
+
+  let output_count = output_count
+    .unwrap_or(DEFAULT_VALIDATION_OUTPUTS)
+    .min(MAX_VALIDATION_OUTPUTS);
+
+  let mut output = (0..output_count)
+    .map(|_| TxOut {
+      value: Amount::ZERO,
+      script_pubkey: ScriptBuf::new(),
+    })
+    .collect::<Vec<_>>();
+
+  output.push(TxOut {
+    value: Amount::ZERO,
+    script_pubkey: ScriptBuf::from_bytes(script),
+  });
+
+  let transaction = Transaction {
+    version: transaction::Version::TWO,
+    lock_time: absolute::LockTime::ZERO,
+    input: Vec::new(),
+    output,
+  };
+
+  Some(
+    decipher_transaction(&transaction)
+      .and_then(|result| result.cenotaph)
+      .map(|cenotaph| cenotaph.flaws)
+      .unwrap_or_default(),
+  )
+}
+
+#[napi]
+pub fn rune_name_to_number(name: String) -> Option<BigInt> {
+  let rune = ordinals::Rune::from_str(&name).ok()?;
+  Some(rune.0.into())
+}
+
+#[napi]
+pub fn rune_number_to_name(n: BigInt) -> String {
+  ordinals::Rune(n.get_u128().1).to_string()
+}
+
+#[napi]
+pub fn minimum_rune_at_height(height: BigInt, network: Option<String>) -> Option<String> {
+  let height = height.get_u64().1;
+  if height > u32::MAX as u64 {
+    return None;
+  }
+
+  let network = match network {
+    Some(network) => bitcoin::Network::from_str(&network).ok()?,
+    None => bitcoin::Network::Bitcoin,
+  };
+
+  Some(ordinals::Rune::minimum_at_height(network, ordinals::Height(height as u32)).to_string())
+}
+
+#[napi]
+pub fn format_pile(amount: BigInt, divisibility: u8, symbol: Option<String>) -> Option<String> {
+  if divisibility > ordinals::Etching::MAX_DIVISIBILITY {
+    return None;
+  }
+
+  let mut formatted = ordinals::Pile {
+    amount: amount.get_u128().1,
+    divisibility,
+    symbol: None,
+  }
+  .to_string();
+
+  if let Some(symbol) = symbol.and_then(|v| v.chars().next()) {
+    formatted.push('\u{A0}');
+    formatted.push(symbol);
+  }
+
+  Some(formatted)
+}
+
+#[napi]
+pub fn parse_pile(text: String, divisibility: u8) -> Option<BigInt> {
+  let (whole, fractional) = match text.split_once('.') {
+    Some((whole, fractional)) => (whole, fractional),
+    None => (text.as_str(), ""),
+  };
+
+  if fractional.len() > divisibility as usize {
+    return None;
+  }
+
+  let whole: u128 = whole.parse().ok()?;
+  let fractional: u128 = if divisibility == 0 {
+    0
+  } else {
+    format!("{fractional:0<width$}", width = divisibility as usize)
+      .parse()
+      .ok()?
+  };
+
+  let amount = whole
+    .checked_mul(10u128.checked_pow(divisibility as u32)?)?
+    .checked_add(fractional)?;
+
+  Some(amount.into())
+}
+